@@ -49,7 +49,12 @@ fn get_or_create_pool(num_threads: usize) -> Result<Arc<rayon::ThreadPool>, Stri
 }
 
 /// Generic Levenshtein implementation for any type that implements PartialEq.
-fn levenshtein_impl<T: PartialEq>(s1: &[T], s2: &[T]) -> usize {
+///
+/// When `transpositions` is true the recurrence becomes the optimal-string-alignment (OSA)
+/// variant, charging a swap of two adjacent units as a single edit instead of two. The OSA
+/// invariant is that no substring is edited more than once, which is sufficient for typo and
+/// OCR use cases.
+fn levenshtein_impl<T: PartialEq>(s1: &[T], s2: &[T], transpositions: bool) -> usize {
     let (us1, us2) = if s1.len() < s2.len() {
         (s2, s1)
     } else {
@@ -67,6 +72,8 @@ fn levenshtein_impl<T: PartialEq>(s1: &[T], s2: &[T]) -> usize {
 
     let mut prev: FastVec<usize> = (0..cols).collect();
     let mut cur: FastVec<usize> = smallvec![0; cols];
+    // Third rolling row holding `D[r-2]`, consulted only for adjacent transpositions.
+    let mut prev2: FastVec<usize> = smallvec![0; cols];
 
     for r in 1..rows {
         cur[0] = r;
@@ -74,13 +81,260 @@ fn levenshtein_impl<T: PartialEq>(s1: &[T], s2: &[T]) -> usize {
             let del_or_ins = std::cmp::min(prev[c] + 1, cur[c - 1] + 1);
             let edit = prev[c - 1] + (if us1[r - 1] == us2[c - 1] { 0 } else { 1 });
             cur[c] = std::cmp::min(del_or_ins, edit);
+            if transpositions
+                && r > 1
+                && c > 1
+                && us1[r - 1] == us2[c - 2]
+                && us1[r - 2] == us2[c - 1]
+            {
+                cur[c] = std::cmp::min(cur[c], prev2[c - 2] + 1);
+            }
         }
+        // Rotate prev2 <- prev <- cur for the next row.
+        std::mem::swap(&mut prev2, &mut prev);
         std::mem::swap(&mut prev, &mut cur);
     }
 
     prev[cols - 1]
 }
 
+/// Banded Levenshtein distance with an early-termination cutoff `k`.
+///
+/// Because any cell with `|r - c| > k` provably holds a value exceeding `k`, only the
+/// diagonal band `|r - c| <= k` is evaluated; cells outside it are held at the sentinel
+/// `k + 1`. The scan aborts as soon as a completed row's minimum exceeds `k`. The return
+/// value is the exact distance when it is `<= k`, otherwise the sentinel `k + 1` (which also
+/// means "further than `k` edits apart").
+fn levenshtein_banded_impl<T: PartialEq>(s1: &[T], s2: &[T], k: usize, transpositions: bool) -> usize {
+    let (us1, us2) = if s1.len() < s2.len() {
+        (s2, s1)
+    } else {
+        (s1, s2)
+    };
+
+    let m = us1.len();
+    let n = us2.len();
+    let sentinel = k.saturating_add(1);
+
+    // The length difference is a lower bound on the distance.
+    if m - n > k {
+        return sentinel;
+    }
+
+    let cols = n + 1;
+    let mut prev: FastVec<usize> = (0..cols).map(|c| if c <= k { c } else { sentinel }).collect();
+    let mut cur: FastVec<usize> = smallvec![sentinel; cols];
+    // Third rolling row holding `D[r-2]`, consulted only for adjacent transpositions.
+    let mut prev2: FastVec<usize> = smallvec![sentinel; cols];
+
+    for r in 1..=m {
+        let lo = r.saturating_sub(k);
+        let hi = std::cmp::min(n, r + k);
+
+        // Reset out-of-band cells to the sentinel so neighbouring reads stay sound.
+        cur[0] = if r <= k { r } else { sentinel };
+        for c in 1..cols {
+            if c < lo || c > hi {
+                cur[c] = sentinel;
+            }
+        }
+
+        let mut row_min = cur[0];
+        for c in std::cmp::max(1, lo)..=hi {
+            let del_or_ins = std::cmp::min(prev[c].saturating_add(1), cur[c - 1].saturating_add(1));
+            let edit = prev[c - 1].saturating_add(if us1[r - 1] == us2[c - 1] { 0 } else { 1 });
+            let mut v = std::cmp::min(del_or_ins, edit);
+            if transpositions
+                && r > 1
+                && c > 1
+                && us1[r - 1] == us2[c - 2]
+                && us1[r - 2] == us2[c - 1]
+            {
+                v = std::cmp::min(v, prev2[c - 2].saturating_add(1));
+            }
+            cur[c] = std::cmp::min(v, sentinel);
+            row_min = std::cmp::min(row_min, cur[c]);
+        }
+
+        // Every value in this completed row already exceeds the cutoff: give up early.
+        if row_min > k {
+            return sentinel;
+        }
+
+        std::mem::swap(&mut prev2, &mut prev);
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    std::cmp::min(prev[n], sentinel)
+}
+
+/// Dispatches to the banded or full implementation depending on whether a cutoff is given.
+fn run_impl<T: PartialEq>(
+    us1: &[T],
+    us2: &[T],
+    transpositions: bool,
+    max_distance: Option<usize>,
+) -> usize {
+    match max_distance {
+        Some(k) => levenshtein_banded_impl(us1, us2, k, transpositions),
+        None => levenshtein_impl(us1, us2, transpositions),
+    }
+}
+
+/// Generic Wagner–Fischer edit-operation backtrace for any `PartialEq` unit type.
+///
+/// Computes the full `(m+1)×(n+1)` distance matrix between `s1` and `s2` and backtracks
+/// from `D[m][n]` to recover one optimal alignment. Each returned tuple is
+/// `(tag, src_index, dest_index)` where `tag` is one of `"equal"`, `"replace"`,
+/// `"delete"`, `"insert"` and the indices are into the segmented unit arrays.
+fn levenshtein_editops_impl<T: PartialEq>(s1: &[T], s2: &[T]) -> Vec<(&'static str, usize, usize)> {
+    let m = s1.len();
+    let n = s2.len();
+
+    // Full O(m·n) matrix — opt-in, so the extra memory is acceptable.
+    let mut d: Vec<FastVec<usize>> = (0..=m)
+        .map(|i| {
+            let mut row: FastVec<usize> = smallvec![0; n + 1];
+            row[0] = i;
+            row
+        })
+        .collect();
+    for j in 0..=n {
+        d[0][j] = j;
+    }
+
+    for i in 1..=m {
+        for j in 1..=n {
+            let cost = if s1[i - 1] == s2[j - 1] { 0 } else { 1 };
+            d[i][j] = std::cmp::min(
+                std::cmp::min(d[i - 1][j] + 1, d[i][j - 1] + 1),
+                d[i - 1][j - 1] + cost,
+            );
+        }
+    }
+
+    // Backtrack from the bottom-right cell, pushing ops in reverse.
+    let mut ops: Vec<(&'static str, usize, usize)> = Vec::new();
+    let (mut i, mut j) = (m, n);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && s1[i - 1] == s2[j - 1] && d[i][j] == d[i - 1][j - 1] {
+            ops.push(("equal", i - 1, j - 1));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && d[i][j] == d[i - 1][j - 1] + 1 {
+            ops.push(("replace", i - 1, j - 1));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && d[i][j] == d[i - 1][j] + 1 {
+            ops.push(("delete", i - 1, j));
+            i -= 1;
+        } else {
+            ops.push(("insert", i, j - 1));
+            j -= 1;
+        }
+    }
+
+    ops.reverse();
+    ops
+}
+
+/// Human-readable list of the accepted segmentation selectors, used in error messages.
+const SEGMENTATION_MODES: &str = "\"char\", \"grapheme\", \"word\"";
+
+/// Validates a segmentation selector, rejecting anything other than the three known modes.
+fn validate_segmentation(segmentation: &str) -> PyResult<()> {
+    match segmentation {
+        "char" | "grapheme" | "word" => Ok(()),
+        other => Err(PyValueError::new_err(format!(
+            "segmentation must be one of {SEGMENTATION_MODES}, got {other:?}"
+        ))),
+    }
+}
+
+/// Segments both strings according to `segmentation` and returns their edit distance.
+///
+/// Assumes `segmentation` has already been validated; any unrecognized value falls back to
+/// character segmentation. `"char"` compares Unicode scalar values, `"grapheme"` compares
+/// Unicode grapheme clusters, and `"word"` compares word tokens (via `unicode_words`).
+///
+/// When `max_distance` is `Some(k)`, a banded dynamic program is used and pairs further apart
+/// than `k` edits report the sentinel `k + 1` instead of their exact distance.
+fn distance_by_segmentation(
+    s1: &str,
+    s2: &str,
+    segmentation: &str,
+    transpositions: bool,
+    max_distance: Option<usize>,
+) -> usize {
+    match segmentation {
+        "grapheme" => {
+            let us1: FastVec<String> = UnicodeSegmentation::graphemes(s1, true)
+                .map(|g| g.to_string())
+                .collect();
+            let us2: FastVec<String> = UnicodeSegmentation::graphemes(s2, true)
+                .map(|g| g.to_string())
+                .collect();
+            run_impl(&us1, &us2, transpositions, max_distance)
+        }
+        "word" => {
+            let us1: FastVec<String> = s1.unicode_words().map(|w| w.to_string()).collect();
+            let us2: FastVec<String> = s2.unicode_words().map(|w| w.to_string()).collect();
+            run_impl(&us1, &us2, transpositions, max_distance)
+        }
+        _ => {
+            let us1: FastVec<char> = s1.chars().collect();
+            let us2: FastVec<char> = s2.chars().collect();
+            run_impl(&us1, &us2, transpositions, max_distance)
+        }
+    }
+}
+
+/// Returns the number of comparison units in each string under `segmentation`.
+///
+/// Used to compute the denominator for [`normalized_levenshtein`].
+fn unit_lengths(s1: &str, s2: &str, segmentation: &str) -> (usize, usize) {
+    match segmentation {
+        "grapheme" => (
+            UnicodeSegmentation::graphemes(s1, true).count(),
+            UnicodeSegmentation::graphemes(s2, true).count(),
+        ),
+        "word" => (s1.unicode_words().count(), s2.unicode_words().count()),
+        _ => (s1.chars().count(), s2.chars().count()),
+    }
+}
+
+/// Segments both strings according to `segmentation` and returns the edit operations.
+///
+/// The companion of [`distance_by_segmentation`] for [`levenshtein_editops`]; see that
+/// function for the meaning of each selector.
+fn editops_by_segmentation(
+    s1: &str,
+    s2: &str,
+    segmentation: &str,
+) -> Vec<(&'static str, usize, usize)> {
+    match segmentation {
+        "grapheme" => {
+            let us1: FastVec<String> = UnicodeSegmentation::graphemes(s1, true)
+                .map(|g| g.to_string())
+                .collect();
+            let us2: FastVec<String> = UnicodeSegmentation::graphemes(s2, true)
+                .map(|g| g.to_string())
+                .collect();
+            levenshtein_editops_impl(&us1, &us2)
+        }
+        "word" => {
+            let us1: FastVec<String> = s1.unicode_words().map(|w| w.to_string()).collect();
+            let us2: FastVec<String> = s2.unicode_words().map(|w| w.to_string()).collect();
+            levenshtein_editops_impl(&us1, &us2)
+        }
+        _ => {
+            let us1: FastVec<char> = s1.chars().collect();
+            let us2: FastVec<char> = s2.chars().collect();
+            levenshtein_editops_impl(&us1, &us2)
+        }
+    }
+}
+
 /// Calculates the Levenshtein distance between two strings.
 ///
 /// The Levenshtein distance is the minimum number of single-character edits
@@ -91,16 +345,44 @@ fn levenshtein_impl<T: PartialEq>(s1: &[T], s2: &[T]) -> usize {
 ///
 /// * `s1` - The first string.
 /// * `s2` - The second string.
-/// * `grapheme_segmentation` - If true, strings are segmented into Unicode Grapheme Clusters before
-///   calculating the distance. This is more accurate for languages with complex scripts but comes
-///   with some performance penalties. Defaults to `false`.
+/// * `segmentation` - How the strings are split into comparison units: `"char"` (Unicode
+///   scalar values, the default), `"grapheme"` (Unicode grapheme clusters, more accurate for
+///   complex scripts at some performance cost), or `"word"` (word tokens, for sentence-level
+///   distances).
+/// * `transpositions` - If true, a swap of two adjacent units is counted as a single edit
+///   (optimal-string-alignment / Damerau–Levenshtein), rather than a deletion plus an
+///   insertion. Defaults to `false`.
+/// * `max_distance` - Optional cutoff `k`. When given, the computation uses a banded dynamic
+///   program that stops early once the distance is known to exceed `k`, and pairs further than
+///   `k` edits apart report the sentinel `k + 1` instead of their exact distance. Defaults to
+///   `None` (no cutoff).
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `segmentation` is not one of `"char"`, `"grapheme"`, or `"word"`.
 ///
 /// # Examples
 ///
 /// ```
 /// use pyo3_levenshtein::levenshtein;
-/// let distance = levenshtein("kitten", "sitting", false);
+/// let distance = levenshtein("kitten", "sitting", "char", false, None).unwrap();
 /// assert_eq!(distance, 3);
+/// // With a cutoff of 1, the distant pair reports the sentinel 2 (= k + 1).
+/// assert_eq!(levenshtein("kitten", "sitting", "char", false, Some(1)).unwrap(), 2);
+/// ```
+///
+/// ```
+/// use pyo3_levenshtein::levenshtein;
+/// // A single adjacent transposition costs 1 edit instead of 2.
+/// assert_eq!(levenshtein("ab", "ba", "char", true, None).unwrap(), 1);
+/// assert_eq!(levenshtein("ab", "ba", "char", false, None).unwrap(), 2);
+/// ```
+///
+/// ```
+/// use pyo3_levenshtein::levenshtein;
+/// // Word-level segmentation counts a one-word substitution as a single edit.
+/// let distance = levenshtein("the quick brown fox", "the slow brown fox", "word", false, None).unwrap();
+/// assert_eq!(distance, 1);
 /// ```
 ///
 /// ```
@@ -108,30 +390,119 @@ fn levenshtein_impl<T: PartialEq>(s1: &[T], s2: &[T]) -> usize {
 /// // "अनुच्छेद" (article) in Hindi has 4 grapheme clusters
 /// // "अनुच्छेद" (article) in Hindi has 7 characters
 /// // "अनुछेद" (article, misspelled) in Hindi has 6 characters
-/// let distance = levenshtein("अनुच्छेद", "अनुछेद", true); // Grapheme segmentation
+/// let distance = levenshtein("अनुच्छेद", "अनुछेद", "grapheme", false, None).unwrap(); // Grapheme segmentation
 /// assert_eq!(distance, 1);
-/// let distance_char = levenshtein("अनुच्छेद", "अनुछेद", false); // Character segmentation
+/// let distance_char = levenshtein("अनुच्छेद", "अनुछेद", "char", false, None).unwrap(); // Character segmentation
 /// assert_eq!(distance_char, 2); // In this specific example, the result is 2 for char segmentation
 /// ```
-#[pyfunction(signature = (s1, s2, grapheme_segmentation = false))]
-pub fn levenshtein(s1: &str, s2: &str, grapheme_segmentation: bool) -> usize {
+#[pyfunction(signature = (s1, s2, segmentation = "char", transpositions = false, max_distance = None))]
+pub fn levenshtein(
+    s1: &str,
+    s2: &str,
+    segmentation: &str,
+    transpositions: bool,
+    max_distance: Option<usize>,
+) -> PyResult<usize> {
+    validate_segmentation(segmentation)?;
+
     if s1 == s2 {
-        return 0;
+        return Ok(0);
     }
 
-    if grapheme_segmentation {
-        let us1: FastVec<String> = UnicodeSegmentation::graphemes(s1, true)
-            .map(|g| g.to_string())
-            .collect();
-        let us2: FastVec<String> = UnicodeSegmentation::graphemes(s2, true)
-            .map(|g| g.to_string())
-            .collect();
-        levenshtein_impl(&us1, &us2)
-    } else {
-        let us1: FastVec<char> = s1.chars().collect();
-        let us2: FastVec<char> = s2.chars().collect();
-        levenshtein_impl(&us1, &us2)
+    Ok(distance_by_segmentation(
+        s1,
+        s2,
+        segmentation,
+        transpositions,
+        max_distance,
+    ))
+}
+
+/// Computes a normalized Levenshtein similarity in `[0.0, 1.0]`.
+///
+/// The score is `1.0 - distance / max(len(s1), len(s2))`, where the lengths are measured in
+/// the same comparison units as the distance. Two empty strings are defined to be perfectly
+/// similar and return `1.0`.
+///
+/// # Arguments
+///
+/// * `s1` - The first string.
+/// * `s2` - The second string.
+/// * `segmentation` - How the strings are split into comparison units: `"char"` (the default),
+///   `"grapheme"`, or `"word"`.
+/// * `transpositions` - If true, adjacent transpositions count as a single edit. Defaults to
+///   `false`.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `segmentation` is not one of `"char"`, `"grapheme"`, or `"word"`.
+///
+/// # Examples
+///
+/// ```
+/// use pyo3_levenshtein::normalized_levenshtein;
+/// // "kitten" -> "sitting" is 3 edits over a max length of 7.
+/// let score = normalized_levenshtein("kitten", "sitting", "char", false).unwrap();
+/// assert!((score - (1.0 - 3.0 / 7.0)).abs() < 1e-12);
+/// assert_eq!(normalized_levenshtein("", "", "char", false).unwrap(), 1.0);
+/// ```
+#[pyfunction(signature = (s1, s2, segmentation = "char", transpositions = false))]
+pub fn normalized_levenshtein(
+    s1: &str,
+    s2: &str,
+    segmentation: &str,
+    transpositions: bool,
+) -> PyResult<f64> {
+    validate_segmentation(segmentation)?;
+
+    let (len1, len2) = unit_lengths(s1, s2, segmentation);
+    let max_len = std::cmp::max(len1, len2);
+    if max_len == 0 {
+        return Ok(1.0);
     }
+
+    let distance = distance_by_segmentation(s1, s2, segmentation, transpositions, None);
+    Ok(1.0 - distance as f64 / max_len as f64)
+}
+
+/// Computes the sequence of edit operations that transforms `s1` into `s2`.
+///
+/// Unlike [`levenshtein`], which returns only the scalar distance, this returns the full
+/// alignment as a list of `(tag, src_index, dest_index)` tuples, where `tag` is one of
+/// `"equal"`, `"replace"`, `"delete"`, or `"insert"`. This is what downstream
+/// text/OCR-alignment tools need to render diffs rather than a single number.
+///
+/// The indices refer into the segmented unit arrays: character positions by default,
+/// grapheme-cluster positions for `"grapheme"`, or word-token positions for `"word"`.
+///
+/// # Arguments
+///
+/// * `s1` - The source string.
+/// * `s2` - The destination string.
+/// * `segmentation` - How the strings are split into comparison units: `"char"` (the
+///   default), `"grapheme"`, or `"word"`.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `segmentation` is not one of `"char"`, `"grapheme"`, or `"word"`.
+///
+/// # Examples
+///
+/// ```python
+/// import pyo3_levenshtein as lev
+///
+/// lev.levenshtein_editops("kitten", "sitting")
+/// # [("replace", 0, 0), ("equal", 1, 1), ("equal", 2, 2), ("equal", 3, 3),
+/// #  ("replace", 4, 4), ("equal", 5, 5), ("insert", 6, 6)]
+/// ```
+#[pyfunction(signature = (s1, s2, segmentation = "char"))]
+pub fn levenshtein_editops(
+    s1: &str,
+    s2: &str,
+    segmentation: &str,
+) -> PyResult<Vec<(&'static str, usize, usize)>> {
+    validate_segmentation(segmentation)?;
+    Ok(editops_by_segmentation(s1, s2, segmentation))
 }
 
 /// Calculates Levenshtein distances for multiple string pairs in parallel.
@@ -144,9 +515,14 @@ pub fn levenshtein(s1: &str, s2: &str, grapheme_segmentation: bool) -> usize {
 ///
 /// * `pairs` - A vector of string pairs (tuples) to process
 /// * `num_threads` - Optional number of threads to use. If None, uses all available CPU cores
-/// * `grapheme_segmentation` - If true, strings are segmented into Unicode Grapheme Clusters before
-///   calculating the distance. This is more accurate for languages with complex scripts but comes
-///   with some performance penalties. Defaults to `false`.
+/// * `segmentation` - How the strings are split into comparison units: `"char"` (the default),
+///   `"grapheme"`, or `"word"`.
+/// * `transpositions` - If true, a swap of two adjacent units is counted as a single edit
+///   (optimal-string-alignment / Damerau–Levenshtein). Defaults to `false`.
+/// * `max_distance` - Optional cutoff `k`. When given, each pair is computed with a banded
+///   dynamic program and any pair further than `k` edits apart reports the sentinel `k + 1`,
+///   enabling fast "is this within `k` edits?" screening over large pair lists. Defaults to
+///   `None` (no cutoff).
 ///
 /// # Returns
 ///
@@ -156,6 +532,7 @@ pub fn levenshtein(s1: &str, s2: &str, grapheme_segmentation: bool) -> usize {
 ///
 /// Returns `PyValueError` if:
 /// * `num_threads` is 0
+/// * `segmentation` is not one of `"char"`, `"grapheme"`, or `"word"`
 /// * Thread pool creation fails
 ///
 /// # Examples
@@ -171,15 +548,19 @@ pub fn levenshtein(s1: &str, s2: &str, grapheme_segmentation: bool) -> usize {
 /// import pyo3_levenshtein as lev
 ///
 /// pairs = [("अनुच्छेद", "अनुछेद")]
-/// distances = lev.levenshtein_batch(pairs, grapheme_segmentation=True)
+/// distances = lev.levenshtein_batch(pairs, segmentation="grapheme")
 /// ```
-#[pyfunction(signature = (pairs, num_threads=None, grapheme_segmentation = false))]
+#[pyfunction(signature = (pairs, num_threads=None, segmentation = "char", transpositions = false, max_distance = None))]
 pub fn levenshtein_batch(
     py: Python<'_>,
     pairs: Vec<(String, String)>,
     num_threads: Option<usize>,
-    grapheme_segmentation: bool,
+    segmentation: &str,
+    transpositions: bool,
+    max_distance: Option<usize>,
 ) -> PyResult<Vec<usize>> {
+    validate_segmentation(segmentation)?;
+
     // Handle empty input
     if pairs.is_empty() {
         return Ok(Vec::new());
@@ -202,7 +583,7 @@ pub fn levenshtein_batch(
             Ok(pool.install(|| {
                 pairs
                     .par_iter()
-                    .map(|(s1, s2)| levenshtein(s1, s2, grapheme_segmentation))
+                    .map(|(s1, s2)| distance_by_segmentation(s1, s2, segmentation, transpositions, max_distance))
                     .collect()
             }))
         } else {
@@ -210,12 +591,161 @@ pub fn levenshtein_batch(
             // This avoids creating a new thread pool on every call
             Ok(pairs
                 .par_iter()
-                .map(|(s1, s2)| levenshtein(s1, s2, grapheme_segmentation))
+                .map(|(s1, s2)| distance_by_segmentation(s1, s2, segmentation, transpositions, max_distance))
                 .collect())
         }
     })
 }
 
+/// Bounded single-row Levenshtein distance used by [`levenshtein_search`].
+///
+/// Maintains one DP row initialized to `0..=query.len()` and advances it one candidate
+/// unit at a time using the standard min-of-three recurrence. Because the row minimum is a
+/// non-decreasing lower bound on the achievable distance, the scan is abandoned as soon as
+/// that minimum exceeds `max_distance`. Returns `Some(distance)` when the final distance is
+/// within `max_distance`, otherwise `None`.
+fn bounded_row_distance<T: PartialEq>(query: &[T], candidate: &[T], max_distance: usize) -> Option<usize> {
+    let cols = query.len() + 1;
+    let mut prev: FastVec<usize> = (0..cols).collect();
+    let mut cur: FastVec<usize> = smallvec![0; cols];
+
+    for (i, cc) in candidate.iter().enumerate() {
+        cur[0] = i + 1;
+        let mut row_min = cur[0];
+        for c in 1..cols {
+            let del_or_ins = std::cmp::min(prev[c] + 1, cur[c - 1] + 1);
+            let edit = prev[c - 1] + (if *cc == query[c - 1] { 0 } else { 1 });
+            cur[c] = std::cmp::min(del_or_ins, edit);
+            row_min = std::cmp::min(row_min, cur[c]);
+        }
+        // The row minimum is a lower bound on the remaining distance; prune if it is
+        // already hopeless.
+        if row_min > max_distance {
+            return None;
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    let distance = prev[cols - 1];
+    if distance <= max_distance {
+        Some(distance)
+    } else {
+        None
+    }
+}
+
+/// Finds every candidate within `max_distance` edits of `query`.
+///
+/// Given one query and a list of candidate strings, returns the matching candidates paired
+/// with their Levenshtein distances — the approximate-match use case behind Levenshtein
+/// automata. Rather than computing a full matrix per candidate, the query is segmented once
+/// and each candidate is scanned left-to-right against a single DP row, abandoning the
+/// candidate as soon as the row minimum exceeds `max_distance` (see [`bounded_row_distance`]).
+///
+/// The candidate scan is parallelized over the cached Rayon thread pool with the GIL
+/// released, making it far faster than calling [`levenshtein`] in a Python loop over a large
+/// dictionary.
+///
+/// # Arguments
+///
+/// * `query` - The string to match against.
+/// * `candidates` - The dictionary of candidate strings to scan.
+/// * `max_distance` - The maximum edit distance (inclusive) for a candidate to match.
+/// * `num_threads` - Optional number of threads to use. If None, uses all available CPU cores.
+/// * `segmentation` - How the strings are split into comparison units: `"char"` (the default),
+///   `"grapheme"`, or `"word"`.
+///
+/// # Returns
+///
+/// A vector of `(candidate, distance)` pairs for the candidates within `max_distance`, in the
+/// input order.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `num_threads` is 0, `segmentation` is invalid, or thread pool
+/// creation fails.
+///
+/// # Examples
+///
+/// ```python
+/// import pyo3_levenshtein as lev
+///
+/// lev.levenshtein_search("kitten", ["sitting", "mitten", "banana"], 2)
+/// # [("mitten", 1)]
+/// ```
+#[pyfunction(signature = (query, candidates, max_distance, num_threads=None, segmentation = "char"))]
+pub fn levenshtein_search(
+    py: Python<'_>,
+    query: &str,
+    candidates: Vec<String>,
+    max_distance: usize,
+    num_threads: Option<usize>,
+    segmentation: &str,
+) -> PyResult<Vec<(String, usize)>> {
+    validate_segmentation(segmentation)?;
+
+    if candidates.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    if let Some(threads) = num_threads {
+        if threads == 0 {
+            return Err(PyValueError::new_err("num_threads must be at least 1"));
+        }
+    }
+
+    py.detach(|| {
+        // Convert the query once; each candidate is segmented inside the parallel scan.
+        let scan = || -> Vec<(String, usize)> {
+            match segmentation {
+                "grapheme" => {
+                    let q: FastVec<String> = UnicodeSegmentation::graphemes(query, true)
+                        .map(|g| g.to_string())
+                        .collect();
+                    candidates
+                        .par_iter()
+                        .filter_map(|cand| {
+                            let c: FastVec<String> =
+                                UnicodeSegmentation::graphemes(cand.as_str(), true)
+                                    .map(|g| g.to_string())
+                                    .collect();
+                            bounded_row_distance(&q, &c, max_distance).map(|d| (cand.clone(), d))
+                        })
+                        .collect()
+                }
+                "word" => {
+                    let q: FastVec<String> = query.unicode_words().map(|w| w.to_string()).collect();
+                    candidates
+                        .par_iter()
+                        .filter_map(|cand| {
+                            let c: FastVec<String> =
+                                cand.unicode_words().map(|w| w.to_string()).collect();
+                            bounded_row_distance(&q, &c, max_distance).map(|d| (cand.clone(), d))
+                        })
+                        .collect()
+                }
+                _ => {
+                    let q: FastVec<char> = query.chars().collect();
+                    candidates
+                        .par_iter()
+                        .filter_map(|cand| {
+                            let c: FastVec<char> = cand.chars().collect();
+                            bounded_row_distance(&q, &c, max_distance).map(|d| (cand.clone(), d))
+                        })
+                        .collect()
+                }
+            }
+        };
+
+        if let Some(threads) = num_threads {
+            let pool = get_or_create_pool(threads).map_err(PyValueError::new_err)?;
+            Ok(pool.install(scan))
+        } else {
+            Ok(scan())
+        }
+    })
+}
+
 #[pymodule]
 mod pyo3_levenshtein {
     #[pymodule_export]
@@ -223,6 +753,15 @@ mod pyo3_levenshtein {
 
     #[pymodule_export]
     use super::levenshtein_batch;
+
+    #[pymodule_export]
+    use super::levenshtein_editops;
+
+    #[pymodule_export]
+    use super::levenshtein_search;
+
+    #[pymodule_export]
+    use super::normalized_levenshtein;
 }
 
 #[cfg(test)]
@@ -231,78 +770,462 @@ mod tests {
 
     #[test]
     fn test_identical_strings() {
-        assert_eq!(levenshtein("hello", "hello", false), 0);
-        assert_eq!(levenshtein("hello", "hello", true), 0);
+        assert_eq!(levenshtein("hello", "hello", "char", false, None).unwrap(), 0);
+        assert_eq!(levenshtein("hello", "hello", "grapheme", false, None).unwrap(), 0);
     }
 
     #[test]
     fn test_empty_strings() {
-        assert_eq!(levenshtein("", "", false), 0);
-        assert_eq!(levenshtein("hello", "", false), 5);
-        assert_eq!(levenshtein("", "world", false), 5);
-        assert_eq!(levenshtein("", "", true), 0);
-        assert_eq!(levenshtein("hello", "", true), 5);
-        assert_eq!(levenshtein("", "world", true), 5);
+        assert_eq!(levenshtein("", "", "char", false, None).unwrap(), 0);
+        assert_eq!(levenshtein("hello", "", "char", false, None).unwrap(), 5);
+        assert_eq!(levenshtein("", "world", "char", false, None).unwrap(), 5);
+        assert_eq!(levenshtein("", "", "grapheme", false, None).unwrap(), 0);
+        assert_eq!(levenshtein("hello", "", "grapheme", false, None).unwrap(), 5);
+        assert_eq!(levenshtein("", "world", "grapheme", false, None).unwrap(), 5);
     }
 
     #[test]
     fn test_single_char_difference() {
-        assert_eq!(levenshtein("kitten", "sitten", false), 1);
-        assert_eq!(levenshtein("kitten", "sitten", true), 1);
+        assert_eq!(levenshtein("kitten", "sitten", "char", false, None).unwrap(), 1);
+        assert_eq!(levenshtein("kitten", "sitten", "grapheme", false, None).unwrap(), 1);
     }
 
     #[test]
     fn test_classic_example() {
-        assert_eq!(levenshtein("kitten", "sitting", false), 3);
-        assert_eq!(levenshtein("kitten", "sitting", true), 3);
+        assert_eq!(levenshtein("kitten", "sitting", "char", false, None).unwrap(), 3);
+        assert_eq!(levenshtein("kitten", "sitting", "grapheme", false, None).unwrap(), 3);
     }
 
     #[test]
     fn test_unicode_char_segmentation() {
-        assert_eq!(levenshtein("café", "cafe", false), 1);
-        assert_eq!(levenshtein("🦀", "🐍", false), 1);
+        assert_eq!(levenshtein("café", "cafe", "char", false, None).unwrap(), 1);
+        assert_eq!(levenshtein("🦀", "🐍", "char", false, None).unwrap(), 1);
         // Test cases where character count != grapheme count
         // "अनुच्छेद" (article) in Hindi has 7 characters
         // "अनुछेद" (article, misspelled) in Hindi has 6 characters
         // Distance by character is 2 (as confirmed by Python)
-        assert_eq!(levenshtein("अनुच्छेद", "अनुछेद", false), 2);
+        assert_eq!(levenshtein("अनुच्छेद", "अनुछेद", "char", false, None).unwrap(), 2);
         // "niño" (child) has 4 characters
         // "nino" has 4 characters
         // The difference is 1 character ('ñ' vs 'n')
-        assert_eq!(levenshtein("niño", "nino", false), 1);
+        assert_eq!(levenshtein("niño", "nino", "char", false, None).unwrap(), 1);
         // Combining characters: "é" is 'e' + combining acute accent (U+0301)
         // Character segmentation: "e\u{0301}" (2 chars) vs "e" (1 char) -> distance 1 (as confirmed by Python)
-        assert_eq!(levenshtein("e\u{0301}", "e", false), 1);
+        assert_eq!(levenshtein("e\u{0301}", "e", "char", false, None).unwrap(), 1);
         // 'ä' is U+00E4 (1 char), 'a\u{0308}' is U+0061 U+0308 (2 chars)
-        assert_eq!(levenshtein("ä", "a\u{0308}", false), 2);
+        assert_eq!(levenshtein("ä", "a\u{0308}", "char", false, None).unwrap(), 2);
     }
 
     #[test]
     fn test_unicode_grapheme_segmentation() {
         // "café" has 4 graphemes, "cafe" has 4 graphemes. Distance 1.
-        assert_eq!(levenshtein("café", "cafe", true), 1);
+        assert_eq!(levenshtein("café", "cafe", "grapheme", false, None).unwrap(), 1);
         // "🦀" has 1 grapheme, "🐍" has 1 grapheme. Distance 1.
-        assert_eq!(levenshtein("🦀", "🐍", true), 1);
+        assert_eq!(levenshtein("🦀", "🐍", "grapheme", false, None).unwrap(), 1);
         // "अनुच्छेद" (article) in Hindi has 4 grapheme clusters
         // "अनुछेद" (article, misspelled) in Hindi has 3 grapheme clusters
         // Distance by grapheme is 1
-        assert_eq!(levenshtein("अनुच्छेद", "अनुछेद", true), 1);
+        assert_eq!(levenshtein("अनुच्छेद", "अनुछेद", "grapheme", false, None).unwrap(), 1);
         // "niño" has 4 graphemes, "nino" has 4 graphemes. Distance 1.
-        assert_eq!(levenshtein("niño", "nino", true), 1);
+        assert_eq!(levenshtein("niño", "nino", "grapheme", false, None).unwrap(), 1);
         // Combining characters: "é" (1 grapheme) vs "e" (1 grapheme) -> distance 1
-        assert_eq!(levenshtein("e\u{0301}", "e", true), 1);
+        assert_eq!(levenshtein("e\u{0301}", "e", "grapheme", false, None).unwrap(), 1);
         // 'ä' is U+00E4 (1 grapheme), 'a\u{0308}' is U+0061 U+0308 (1 grapheme). Distance 1.
         // Even though they are canonically equivalent, `unicode-segmentation` considers them
         // distinct grapheme clusters because their byte representation is different.
         // A true canonical equivalence check would require normalization, which is beyond
         // simple grapheme segmentation.
-        assert_eq!(levenshtein("ä", "a\u{0308}", true), 1);
+        assert_eq!(levenshtein("ä", "a\u{0308}", "grapheme", false, None).unwrap(), 1);
 
         // Test with a more complex grapheme cluster example
         // "👩‍👩‍👧‍👦" (family: woman, woman, girl, boy) is 1 grapheme cluster (using ZWJ)
         let s1 = "👩‍👩‍👧‍👦"; // Family emoji (1 grapheme cluster)
         let s2 = "👨‍👩‍👧‍👦"; // Family emoji (different head, 1 grapheme cluster)
-        assert_eq!(levenshtein(s1, s2, true), 1); // Expected 1 edit to change a component
+        assert_eq!(levenshtein(s1, s2, "grapheme", false, None).unwrap(), 1); // Expected 1 edit to change a component
+    }
+}
+
+#[cfg(test)]
+mod transposition_tests {
+    use super::*;
+
+    #[test]
+    fn test_adjacent_swap_is_one_edit() {
+        assert_eq!(levenshtein("ab", "ba", "char", true, None).unwrap(), 1);
+        assert_eq!(levenshtein("ab", "ba", "char", false, None).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_transposition_in_word() {
+        // "teh" -> "the" is a single adjacent transposition under OSA.
+        assert_eq!(levenshtein("teh", "the", "char", true, None).unwrap(), 1);
+        assert_eq!(levenshtein("teh", "the", "char", false, None).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_transposition_never_worse_than_plain() {
+        let cases = [("kitten", "sitting"), ("saturday", "sunday"), ("abcdef", "abdcef")];
+        for (a, b) in cases {
+            assert!(levenshtein(a, b, "char", true, None).unwrap() <= levenshtein(a, b, "char", false, None).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_transposition_grapheme() {
+        assert_eq!(levenshtein("café", "café", "grapheme", true, None).unwrap(), 0);
+        // Swap the last two graphemes.
+        assert_eq!(levenshtein("abé", "aéb", "grapheme", true, None).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_batch_transpositions() {
+        Python::initialize();
+        Python::attach(|py| {
+            let pairs = vec![
+                ("ab".to_string(), "ba".to_string()),
+                ("teh".to_string(), "the".to_string()),
+            ];
+            let result = levenshtein_batch(py, pairs, None, "char", true, None).unwrap();
+            assert_eq!(result, vec![1, 1]);
+        });
+    }
+}
+
+#[cfg(test)]
+mod editops_tests {
+    use super::*;
+
+    /// Replaying the character-level editops must reproduce `s2`, and the number of
+    /// non-`equal` ops must equal the Levenshtein distance.
+    fn check(s1: &str, s2: &str) {
+        let ops = levenshtein_editops(s1, s2, "char").unwrap();
+        let non_equal = ops.iter().filter(|(tag, _, _)| *tag != "equal").count();
+        assert_eq!(non_equal, levenshtein(s1, s2, "char", false, None).unwrap());
+
+        let dst: Vec<char> = s2.chars().collect();
+        let mut out = String::new();
+        for (tag, _si, di) in &ops {
+            match *tag {
+                "equal" | "replace" | "insert" => out.push(dst[*di]),
+                "delete" => {}
+                _ => unreachable!(),
+            }
+        }
+        assert_eq!(out, s2);
+    }
+
+    #[test]
+    fn test_editops_classic_example() {
+        let ops = levenshtein_editops("kitten", "sitting", "char").unwrap();
+        assert_eq!(
+            ops,
+            vec![
+                ("replace", 0, 0),
+                ("equal", 1, 1),
+                ("equal", 2, 2),
+                ("equal", 3, 3),
+                ("replace", 4, 4),
+                ("equal", 5, 5),
+                ("insert", 6, 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_editops_identical() {
+        let ops = levenshtein_editops("abc", "abc", "char").unwrap();
+        assert!(ops.iter().all(|(tag, _, _)| *tag == "equal"));
+        assert_eq!(ops.len(), 3);
+    }
+
+    #[test]
+    fn test_editops_empty_inputs() {
+        assert_eq!(
+            levenshtein_editops("", "ab", "char").unwrap(),
+            vec![("insert", 0, 0), ("insert", 0, 1)]
+        );
+        assert_eq!(
+            levenshtein_editops("ab", "", "char").unwrap(),
+            vec![("delete", 0, 0), ("delete", 1, 0)]
+        );
+        assert!(levenshtein_editops("", "", "char").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_editops_reconstruct() {
+        check("kitten", "sitting");
+        check("saturday", "sunday");
+        check("café", "cafe");
+        check("", "world");
+        check("abc", "");
+    }
+
+    #[test]
+    fn test_editops_grapheme() {
+        // Grapheme segmentation collapses "é" into a single unit.
+        let ops = levenshtein_editops("café", "cafe", "grapheme").unwrap();
+        let non_equal = ops.iter().filter(|(tag, _, _)| *tag != "equal").count();
+        assert_eq!(non_equal, 1);
+        assert_eq!(ops.len(), 4);
+    }
+}
+
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+
+    fn run(
+        query: &str,
+        candidates: &[&str],
+        max_distance: usize,
+        segmentation: &str,
+    ) -> Vec<(String, usize)> {
+        Python::initialize();
+        Python::attach(|py| {
+            let cands: Vec<String> = candidates.iter().map(|s| s.to_string()).collect();
+            levenshtein_search(py, query, cands, max_distance, None, segmentation).unwrap()
+        })
+    }
+
+    #[test]
+    fn test_search_basic() {
+        let result = run("kitten", &["sitting", "mitten", "banana"], 2, "char");
+        assert_eq!(result, vec![("mitten".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_search_preserves_order_and_filters() {
+        let result = run("kitten", &["sitting", "mitten", "kitten"], 1, "char");
+        assert_eq!(
+            result,
+            vec![("mitten".to_string(), 1), ("kitten".to_string(), 0)]
+        );
+    }
+
+    #[test]
+    fn test_search_empty_candidates() {
+        let result = run("kitten", &[], 2, "char");
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_search_zero_distance_is_exact() {
+        let result = run("hello", &["hello", "hell", "jello"], 0, "char");
+        assert_eq!(result, vec![("hello".to_string(), 0)]);
+    }
+
+    #[test]
+    fn test_search_matches_levenshtein() {
+        let candidates = ["sitting", "mitten", "bitten", "written"];
+        let result = run("kitten", &candidates, 3, "char");
+        for (cand, dist) in &result {
+            assert_eq!(*dist, levenshtein("kitten", cand, "char", false, None).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_search_grapheme() {
+        let result = run("café", &["cafe", "caff", "latte"], 1, "grapheme");
+        assert_eq!(
+            result,
+            vec![("cafe".to_string(), 1), ("caff".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn test_search_invalid_thread_count() {
+        Python::initialize();
+        Python::attach(|py| {
+            let result = levenshtein_search(py, "a", vec!["b".to_string()], 1, Some(0), "char");
+            assert!(result.is_err());
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("num_threads must be at least 1")
+            );
+        });
+    }
+}
+
+#[cfg(test)]
+mod word_segmentation_tests {
+    use super::*;
+
+    #[test]
+    fn test_word_single_substitution() {
+        // One word differs between the two sentences.
+        assert_eq!(
+            levenshtein("the quick brown fox", "the slow brown fox", "word", false, None).unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_word_insertion_and_deletion() {
+        assert_eq!(
+            levenshtein("hello world", "hello", "word", false, None).unwrap(),
+            1
+        );
+        assert_eq!(
+            levenshtein("hello", "hello there world", "word", false, None).unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_word_ignores_character_noise() {
+        // Char distance counts every edited character (2 here), but at word granularity only
+        // one token changed.
+        assert_eq!(
+            levenshtein("color of the sky", "colours of the sky", "char", false, None).unwrap(),
+            2
+        );
+        assert_eq!(
+            levenshtein("color of the sky", "colours of the sky", "word", false, None).unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_word_editops() {
+        let ops = levenshtein_editops("the slow fox", "the quick fox", "word").unwrap();
+        assert_eq!(
+            ops,
+            vec![("equal", 0, 0), ("replace", 1, 1), ("equal", 2, 2)]
+        );
+    }
+
+    #[test]
+    fn test_word_search() {
+        Python::initialize();
+        Python::attach(|py| {
+            let candidates = vec![
+                "the quick brown fox".to_string(),
+                "the slow brown fox".to_string(),
+                "a completely different sentence".to_string(),
+            ];
+            let result =
+                levenshtein_search(py, "the quick brown fox", candidates, 1, None, "word").unwrap();
+            assert_eq!(
+                result,
+                vec![
+                    ("the quick brown fox".to_string(), 0),
+                    ("the slow brown fox".to_string(), 1),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn test_invalid_segmentation_is_rejected() {
+        assert!(levenshtein("a", "b", "bytes", false, None).is_err());
+        Python::initialize();
+        Python::attach(|py| {
+            let pairs = vec![("a".to_string(), "b".to_string())];
+            let result = levenshtein_batch(py, pairs, None, "bytes", false, None);
+            assert!(result.is_err());
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("segmentation must be one of")
+            );
+        });
+    }
+}
+
+#[cfg(test)]
+mod normalized_tests {
+    use super::*;
+
+    #[test]
+    fn test_normalized_identical_is_one() {
+        assert_eq!(normalized_levenshtein("hello", "hello", "char", false).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_normalized_both_empty_is_one() {
+        assert_eq!(normalized_levenshtein("", "", "char", false).unwrap(), 1.0);
+        assert_eq!(normalized_levenshtein("", "", "grapheme", false).unwrap(), 1.0);
+        assert_eq!(normalized_levenshtein("", "", "word", false).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_normalized_completely_different() {
+        // No shared characters, equal lengths -> similarity 0.
+        assert_eq!(normalized_levenshtein("abc", "xyz", "char", false).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_normalized_partial() {
+        // 3 edits over a max length of 7.
+        let score = normalized_levenshtein("kitten", "sitting", "char", false).unwrap();
+        assert!((score - (1.0 - 3.0 / 7.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_normalized_word_segmentation() {
+        // One of four words differs.
+        let score =
+            normalized_levenshtein("the quick brown fox", "the slow brown fox", "word", false)
+                .unwrap();
+        assert!((score - 0.75).abs() < 1e-12);
+    }
+}
+
+#[cfg(test)]
+mod cutoff_tests {
+    use super::*;
+
+    #[test]
+    fn test_cutoff_within_returns_exact() {
+        assert_eq!(levenshtein("kitten", "sitting", "char", false, Some(3)).unwrap(), 3);
+        assert_eq!(levenshtein("kitten", "sitting", "char", false, Some(5)).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_cutoff_exceeded_returns_sentinel() {
+        // True distance is 3; with k = 1 the sentinel k + 1 = 2 is reported.
+        assert_eq!(levenshtein("kitten", "sitting", "char", false, Some(1)).unwrap(), 2);
+        // Length difference alone exceeds the cutoff.
+        assert_eq!(levenshtein("abc", "abcdefgh", "char", false, Some(2)).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_cutoff_matches_unbounded_when_within() {
+        let cases = [
+            ("kitten", "sitting"),
+            ("saturday", "sunday"),
+            ("flaw", "lawn"),
+            ("", "abc"),
+            ("abc", ""),
+            ("same", "same"),
+        ];
+        for (a, b) in cases {
+            let exact = levenshtein(a, b, "char", false, None).unwrap();
+            let bounded = levenshtein(a, b, "char", false, Some(exact)).unwrap();
+            assert_eq!(bounded, exact);
+        }
+    }
+
+    #[test]
+    fn test_cutoff_with_transpositions() {
+        assert_eq!(levenshtein("ab", "ba", "char", true, Some(1)).unwrap(), 1);
+        assert_eq!(levenshtein("ab", "ba", "char", false, Some(1)).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_batch_cutoff_sentinel() {
+        Python::initialize();
+        Python::attach(|py| {
+            let pairs = vec![
+                ("kitten".to_string(), "sitten".to_string()), // distance 1
+                ("kitten".to_string(), "sitting".to_string()), // distance 3 -> sentinel
+            ];
+            let result = levenshtein_batch(py, pairs, None, "char", false, Some(1)).unwrap();
+            assert_eq!(result, vec![1, 2]);
+        });
     }
 }
 
@@ -315,9 +1238,9 @@ mod batch_tests {
         Python::initialize();
         Python::attach(|py| {
             let pairs: Vec<(String, String)> = vec![];
-            let result = levenshtein_batch(py, pairs.clone(), None, false).unwrap();
+            let result = levenshtein_batch(py, pairs.clone(), None, "char", false, None).unwrap();
             assert_eq!(result, Vec::<usize>::new());
-            let result_grapheme = levenshtein_batch(py, pairs, None, true).unwrap();
+            let result_grapheme = levenshtein_batch(py, pairs, None, "grapheme", false, None).unwrap();
             assert_eq!(result_grapheme, Vec::<usize>::new());
         });
     }
@@ -327,9 +1250,9 @@ mod batch_tests {
         Python::initialize();
         Python::attach(|py| {
             let pairs = vec![("kitten".to_string(), "sitting".to_string())];
-            let result_char = levenshtein_batch(py, pairs.clone(), None, false).unwrap();
+            let result_char = levenshtein_batch(py, pairs.clone(), None, "char", false, None).unwrap();
             assert_eq!(result_char, vec![3]);
-            let result_grapheme = levenshtein_batch(py, pairs, None, true).unwrap();
+            let result_grapheme = levenshtein_batch(py, pairs, None, "grapheme", false, None).unwrap();
             assert_eq!(result_grapheme, vec![3]);
         });
     }
@@ -344,7 +1267,7 @@ mod batch_tests {
                 ("".to_string(), "world".to_string()),
                 ("café".to_string(), "cafe".to_string()),
             ];
-            let result_char = levenshtein_batch(py, pairs_char, None, false).unwrap();
+            let result_char = levenshtein_batch(py, pairs_char, None, "char", false, None).unwrap();
             assert_eq!(result_char, vec![3, 0, 5, 1]);
 
             let pairs_grapheme = vec![
@@ -354,7 +1277,7 @@ mod batch_tests {
                 ("ä".to_string(), "a\u{0308}".to_string()), // Grapheme diff 1
                 ("👩‍👩‍👧‍👦".to_string(), "👨‍👩‍👧‍👦".to_string()),      // Grapheme diff 1
             ];
-            let result_grapheme = levenshtein_batch(py, pairs_grapheme, None, true).unwrap();
+            let result_grapheme = levenshtein_batch(py, pairs_grapheme, None, "grapheme", false, None).unwrap();
             assert_eq!(result_grapheme, vec![1, 1, 1, 1, 1]);
         });
     }
@@ -367,15 +1290,15 @@ mod batch_tests {
                 ("kitten".to_string(), "sitting".to_string()),
                 ("hello".to_string(), "world".to_string()),
             ];
-            let result_char = levenshtein_batch(py, pairs.clone(), Some(2), false).unwrap();
+            let result_char = levenshtein_batch(py, pairs.clone(), Some(2), "char", false, None).unwrap();
             assert_eq!(result_char.len(), 2);
-            assert_eq!(result_char[0], levenshtein("kitten", "sitting", false));
-            assert_eq!(result_char[1], levenshtein("hello", "world", false));
+            assert_eq!(result_char[0], levenshtein("kitten", "sitting", "char", false, None).unwrap());
+            assert_eq!(result_char[1], levenshtein("hello", "world", "char", false, None).unwrap());
 
-            let result_grapheme = levenshtein_batch(py, pairs, Some(2), true).unwrap();
+            let result_grapheme = levenshtein_batch(py, pairs, Some(2), "grapheme", false, None).unwrap();
             assert_eq!(result_grapheme.len(), 2);
-            assert_eq!(result_grapheme[0], levenshtein("kitten", "sitting", true));
-            assert_eq!(result_grapheme[1], levenshtein("hello", "world", true));
+            assert_eq!(result_grapheme[0], levenshtein("kitten", "sitting", "grapheme", false, None).unwrap());
+            assert_eq!(result_grapheme[1], levenshtein("hello", "world", "grapheme", false, None).unwrap());
         });
     }
 
@@ -384,7 +1307,7 @@ mod batch_tests {
         Python::initialize();
         Python::attach(|py| {
             let pairs = vec![("test".to_string(), "test".to_string())];
-            let result = levenshtein_batch(py, pairs.clone(), Some(0), false);
+            let result = levenshtein_batch(py, pairs.clone(), Some(0), "char", false, None);
             assert!(result.is_err());
             assert!(
                 result
@@ -393,7 +1316,7 @@ mod batch_tests {
                     .contains("num_threads must be at least 1")
             );
 
-            let result_grapheme = levenshtein_batch(py, pairs, Some(0), true);
+            let result_grapheme = levenshtein_batch(py, pairs, Some(0), "grapheme", false, None);
             assert!(result_grapheme.is_err());
             assert!(
                 result_grapheme
@@ -414,7 +1337,7 @@ mod batch_tests {
                 ("अनुच्छेद".to_string(), "अनुछेद".to_string()),
                 ("e\u{0301}".to_string(), "e".to_string()),
             ];
-            let result = levenshtein_batch(py, pairs, None, true).unwrap();
+            let result = levenshtein_batch(py, pairs, None, "grapheme", false, None).unwrap();
             assert_eq!(result, vec![1, 1, 1, 1]);
         });
     }
@@ -435,16 +1358,16 @@ mod batch_tests {
 
             // Test with char segmentation
             let batch_results_char =
-                levenshtein_batch(py, test_cases.clone(), None, false).unwrap();
+                levenshtein_batch(py, test_cases.clone(), None, "char", false, None).unwrap();
             for (i, (s1, s2)) in test_cases.iter().enumerate() {
-                assert_eq!(batch_results_char[i], levenshtein(s1, s2, false));
+                assert_eq!(batch_results_char[i], levenshtein(s1, s2, "char", false, None).unwrap());
             }
 
             // Test with grapheme segmentation
             let batch_results_grapheme =
-                levenshtein_batch(py, test_cases.clone(), None, true).unwrap();
+                levenshtein_batch(py, test_cases.clone(), None, "grapheme", false, None).unwrap();
             for (i, (s1, s2)) in test_cases.iter().enumerate() {
-                assert_eq!(batch_results_grapheme[i], levenshtein(s1, s2, true));
+                assert_eq!(batch_results_grapheme[i], levenshtein(s1, s2, "grapheme", false, None).unwrap());
             }
         });
     }